@@ -4,11 +4,14 @@
 //! and provides extra list-like utility methods. Veclite also does not need `std`, and so can run without an operating system.
 //!
 //! ## Features
-//! - Implements `Display` for space-separated formatting
+//! - Implements `Display` for space-separated formatting, with a configurable separator
 //! - Retains all `Vec<T>` methods via `Deref`
 //! - Adds `.prepend()` for list-style front insertion
 //! - Short alias [`Vel`] for ergonomic use
 //! - `vel![]` macro for concise construction
+//! - Optional `std` feature for helpers that need the standard library, such as `HashMap` conversions
+//! - Optional `arc` feature for `Arc`-based sharing
+//! - Optional `rand` feature for random sampling helpers
 //!
 //! ## Example
 //! ```
@@ -21,48 +24,2315 @@
 
 #![no_std]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+use alloc::collections::{TryReserveError, VecDeque};
+use alloc::string::String;
 use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::fmt::{self, Display, Formatter};
-use core::ops::{Deref, DerefMut};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Deref, DerefMut, Mul, RangeBounds};
 
-/// A lightweight wrapper around `Vec<T>` that implements `Display` for space-separated formatting,
+/// A lightweight wrapper around `Vec<T>` that implements `Display` (space-separated by default),
 /// and provides ergonomic list-like utilities.
 ///
 /// Use [`Vel`] for a short alias, and [`vel![]`](macro@vel) for convenient construction.
-#[derive(Debug, PartialEq, Clone, Default)]
-pub struct Veclite<T>(pub Vec<T>);
+///
+/// The second field holds the separator used by `Display`; set it with
+/// [`separator`](Veclite::separator). Because that field is private, lists are built
+/// with [`From`], [`new`](Veclite::new) or [`vel![]`](macro@vel) rather than the tuple
+/// constructor, and the inner `Vec<T>` is taken out with [`into_vec`](Veclite::into_vec)
+/// rather than a `Veclite(v)` pattern. This changed in 2.0.
+#[derive(Clone)]
+pub struct Veclite<T>(pub Vec<T>, &'static str);
+
+/// Separator used by `Display` unless one is configured with [`Veclite::separator`].
+const DEFAULT_SEPARATOR: &str = " ";
+
+impl<T> Veclite<T> {
+    /// Creates a new, empty `Veclite<T>`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::Vel;
+    /// let v: Vel<i32> = Vel::new();
+    /// assert!(v.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Veclite::wrap(Vec::new())
+    }
+
+    /// Wraps `v` with the default separator.
+    const fn wrap(v: Vec<T>) -> Self {
+        Veclite(v, DEFAULT_SEPARATOR)
+    }
+
+    /// Sets the separator that `Display` places between elements, replacing the
+    /// default single space.
+    ///
+    /// The separator stays with the list through in-place changes and operations that
+    /// consume it, such as `+`, `+=`, `*`, [`map_in_place`](Self::map_in_place), and
+    /// the `Err` returned by [`try_into_array`](Self::try_into_array). Methods that
+    /// build a separate list from `&self` return it with the default separator.
+    /// Equality ignores the separator.
+    ///
+    /// Only `Display` and [`into_display`](Self::into_display) use the separator. The
+    /// `display_*` adapters ignore it: they separate elements with a single space
+    /// unless their own docs describe a different layout.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3].separator(",");
+    /// assert_eq!(format!("{}", v), "1,2,3");
+    /// assert_eq!(format!("{}", v.display_collapsed()), "1 2 3");
+    /// assert_eq!(v, vel![1, 2, 3]);
+    ///
+    /// let joined = vel![1, 2].separator(",") + vel![3];
+    /// assert_eq!(format!("{}", joined), "1,2,3");
+    /// assert_eq!(format!("{}", joined.tail()), "2 3");
+    /// ```
+    pub fn separator(mut self, sep: &'static str) -> Self {
+        self.1 = sep;
+        self
+    }
+
+    /// Returns a reference to the underlying `Vec<T>`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2];
+    /// assert_eq!(v.as_vec(), &vec![1, 2]);
+    /// ```
+    pub fn as_vec(&self) -> &Vec<T> {
+        &self.0
+    }
+
+    /// Consumes the list and returns the underlying `Vec<T>`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2].into_vec(), vec![1, 2]);
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        self.0
+    }
+
+    /// Creates a list containing exactly one element.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::Vel;
+    /// let v = Vel::singleton(7);
+    /// assert_eq!(v.len(), 1);
+    /// assert_eq!(format!("{}", v), "7");
+    /// ```
+    pub fn singleton(x: T) -> Self {
+        Veclite::wrap(alloc::vec![x])
+    }
+
+    /// Creates a list containing exactly two elements, in order.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::Vel;
+    /// let v = Vel::pair("left", "right");
+    /// assert_eq!(v.len(), 2);
+    /// assert_eq!(format!("{}", v), "left right");
+    /// ```
+    pub fn pair(a: T, b: T) -> Self {
+        Veclite::wrap(alloc::vec![a, b])
+    }
+
+    /// Builds a list from `iter` with its items in reverse order.
+    ///
+    /// The items are collected once and reversed in place, without an intermediate list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::Vel;
+    /// let v = Vel::from_iter_rev(1..=3);
+    /// assert_eq!(format!("{}", v), "3 2 1");
+    /// ```
+    pub fn from_iter_rev<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut v: Vec<T> = iter.into_iter().collect();
+        v.reverse();
+        Veclite::wrap(v)
+    }
+
+    /// Creates an empty list with room for at least `cap` elements, returning an error
+    /// instead of aborting if the allocation fails.
+    ///
+    /// The error is returned when `cap` overflows the maximum allocation size or the
+    /// allocator reports failure, which lets `no_std` targets recover from OOM.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::Vel;
+    /// let v = Vel::<u32>::try_with_capacity(16).unwrap();
+    /// assert!(v.is_empty());
+    /// assert!(v.capacity() >= 16);
+    ///
+    /// assert!(Vel::<u32>::try_with_capacity(usize::MAX).is_err());
+    /// ```
+    pub fn try_with_capacity(cap: usize) -> Result<Self, TryReserveError> {
+        let mut v = Vec::new();
+        v.try_reserve(cap)?;
+        Ok(Veclite::wrap(v))
+    }
+
+    /// Creates a list with room for at least `capacity` elements, then fills it from
+    /// `iter`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::Vel;
+    /// let v = Vel::with_elements(32, 1..=3);
+    /// assert_eq!(format!("{}", v), "1 2 3");
+    /// assert!(v.capacity() >= 32);
+    /// ```
+    pub fn with_elements<I: IntoIterator<Item = T>>(capacity: usize, iter: I) -> Self {
+        let mut v = Vec::with_capacity(capacity);
+        v.extend(iter);
+        Veclite::wrap(v)
+    }
+
+    /// Prepends a value to the front of the list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::Vel;
+    /// let mut v = Vel::new();
+    /// v.push(2);
+    /// v.prepend(1);
+    /// assert_eq!(format!("{}", v), "1 2");
+    /// ```
+    pub fn prepend(&mut self, value: T) {
+        self.0.insert(0, value);
+    }
+
+    /// Returns the index of the first element for which `pred` returns `false`.
+    ///
+    /// The list must already be partitioned by `pred`: every element for which it
+    /// returns `true` comes before every element for which it returns `false`.
+    /// If the predicate is not monotonic in this way, the result is unspecified.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3, 4, 5];
+    /// assert_eq!(v.partition_point(|&x| x < 3), 2);
+    /// ```
+    pub fn partition_point<F: FnMut(&T) -> bool>(&self, pred: F) -> usize {
+        self.0.partition_point(pred)
+    }
+
+    /// Returns `true` if the elements are in non-decreasing order.
+    ///
+    /// Empty and single-element lists are trivially sorted.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// assert!(vel![1, 2, 2, 3].is_sorted());
+    /// assert!(vel![4, 4, 4].is_sorted());
+    /// assert!(!vel![3, 1, 2].is_sorted());
+    /// assert!(Vel::<i32>::new().is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Returns `true` if `cmp` holds for every pair of adjacent elements.
+    ///
+    /// `cmp(a, b)` should return `true` when `a` may come before `b`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![3, 2, 2, 1];
+    /// assert!(v.is_sorted_by(|a, b| a >= b));
+    /// assert!(!v.is_sorted_by(|a, b| a <= b));
+    /// ```
+    pub fn is_sorted_by<F: FnMut(&T, &T) -> bool>(&self, mut cmp: F) -> bool {
+        self.0.windows(2).all(|w| cmp(&w[0], &w[1]))
+    }
+
+    /// Returns the indices that would sort the list in ascending order.
+    ///
+    /// The sort is stable, so equal elements keep their original relative order.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![30, 10, 20];
+    /// assert_eq!(v.argsort(), vel![1, 2, 0]);
+    /// ```
+    pub fn argsort(&self) -> Veclite<usize>
+    where
+        T: Ord,
+    {
+        let mut indices: Vec<usize> = (0..self.0.len()).collect();
+        indices.sort_by_key(|&i| &self.0[i]);
+        Veclite::wrap(indices)
+    }
+
+    /// Reorders the list in place so that element `i` becomes the element
+    /// previously at `perm[i]`.
+    ///
+    /// This is the companion of [`argsort`](Self::argsort): applying the result of
+    /// `argsort` sorts the list.
+    ///
+    /// # Panics
+    /// Panics if `perm` is not a permutation of `0..self.len()`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel!['a', 'b', 'c'];
+    /// v.apply_permutation(&[2, 0, 1]);
+    /// assert_eq!(v, vel!['c', 'a', 'b']);
+    ///
+    /// let mut n = vel![30, 10, 20, 10];
+    /// let order = n.argsort();
+    /// n.apply_permutation(&order);
+    /// assert_eq!(n, vel![10, 10, 20, 30]);
+    /// ```
+    pub fn apply_permutation(&mut self, perm: &[usize]) {
+        let len = self.0.len();
+        assert_eq!(
+            perm.len(),
+            len,
+            "permutation length {} does not match list length {}",
+            perm.len(),
+            len
+        );
+        let mut done = alloc::vec![false; len];
+        for &p in perm {
+            assert!(
+                p < len,
+                "permutation index {} out of range for length {}",
+                p,
+                len
+            );
+            assert!(!done[p], "permutation index {} appears more than once", p);
+            done[p] = true;
+        }
+        done.fill(false);
+        for start in 0..len {
+            if done[start] {
+                continue;
+            }
+            let mut j = start;
+            loop {
+                done[j] = true;
+                let k = perm[j];
+                if k == start {
+                    break;
+                }
+                self.0.swap(j, k);
+                j = k;
+            }
+        }
+    }
+
+    /// Returns a `Display` adapter that writes `prefix`, the elements separated by
+    /// `sep`, then `suffix`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// let v = vel![1, 2, 3];
+    /// assert_eq!(format!("{}", v.display_wrapped("(", ", ", ")")), "(1, 2, 3)");
+    ///
+    /// let empty: Vel<i32> = vel![];
+    /// assert_eq!(format!("{}", empty.display_wrapped("(", ", ", ")")), "()");
+    /// ```
+    pub fn display_wrapped<'a>(
+        &'a self,
+        prefix: &'a str,
+        sep: &'a str,
+        suffix: &'a str,
+    ) -> impl Display + 'a
+    where
+        T: Display,
+    {
+        display_fn(move |f| {
+            f.write_str(prefix)?;
+            write_joined(f, &self.0, sep)?;
+            f.write_str(suffix)
+        })
+    }
+
+    /// Consumes the list and returns its `Display` rendering.
+    ///
+    /// Produces the same text as `format!("{}", v)`, including any separator set with
+    /// [`separator`](Self::separator), but takes `self` by value so the list can be
+    /// moved straight into a formatting step.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3];
+    /// let expected = format!("{}", v);
+    /// assert_eq!(v.into_display(), expected);
+    /// assert_eq!(vel![1, 2, 3].separator(",").into_display(), "1,2,3");
+    /// ```
+    pub fn into_display(self) -> String
+    where
+        T: Display,
+    {
+        alloc::format!("{}", self)
+    }
+
+    /// Returns a reference to the element at `index`, or `default` if out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3];
+    /// assert_eq!(v.get_or(1, &0), &2);
+    /// assert_eq!(v.get_or(10, &0), &0);
+    /// ```
+    pub fn get_or<'a>(&'a self, index: usize, default: &'a T) -> &'a T {
+        self.0.get(index).unwrap_or(default)
+    }
+
+    /// Returns a clone of the element at `index`, or `default` if out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3];
+    /// assert_eq!(v.get_cloned_or(2, 0), 3);
+    /// assert_eq!(v.get_cloned_or(3, 0), 0);
+    /// ```
+    pub fn get_cloned_or(&self, index: usize, default: T) -> T
+    where
+        T: Clone,
+    {
+        self.0.get(index).cloned().unwrap_or(default)
+    }
+
+    /// Moves the first element matching `pred` to the front of the list, keeping the
+    /// order of the others.
+    ///
+    /// Returns `true` if a matching element was found, `false` if the list is unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![1, 2, 3, 4];
+    /// assert!(v.move_to_front(|&x| x == 3));
+    /// assert_eq!(v, vel![3, 1, 2, 4]);
+    /// assert!(!v.move_to_front(|&x| x == 9));
+    /// ```
+    pub fn move_to_front<F: FnMut(&T) -> bool>(&mut self, pred: F) -> bool {
+        match self.0.iter().position(pred) {
+            Some(i) => {
+                self.0[..=i].rotate_right(1);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Clears the list and refills it from `iter`, reusing the existing allocation.
+    ///
+    /// Capacity only grows when `iter` yields more items than currently fit.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::Vel;
+    /// let mut v = Vel::new();
+    /// v.collect_into(0..10);
+    /// let cap = v.capacity();
+    ///
+    /// v.collect_into(0..3);
+    /// assert_eq!(format!("{}", v), "0 1 2");
+    /// assert_eq!(v.capacity(), cap);
+    ///
+    /// v.collect_into(5..13);
+    /// assert_eq!(v.len(), 8);
+    /// assert_eq!(v.capacity(), cap);
+    /// ```
+    pub fn collect_into<I: IntoIterator<Item = T>>(&mut self, iter: I) -> &mut Self {
+        self.0.clear();
+        self.0.extend(iter);
+        self
+    }
+
+    /// Returns the slice `start..end`, clamping both bounds to the list length instead
+    /// of panicking.
+    ///
+    /// An inverted range (`start > end`) yields an empty slice.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3, 4];
+    /// assert_eq!(v.slice_clamped(1, 3), &[2, 3]);
+    /// assert_eq!(v.slice_clamped(2, 100), &[3, 4]);
+    /// assert!(v.slice_clamped(3, 1).is_empty());
+    /// assert!(v.slice_clamped(10, 20).is_empty());
+    /// ```
+    pub fn slice_clamped(&self, start: usize, end: usize) -> &[T] {
+        let len = self.0.len();
+        let end = end.min(len);
+        let start = start.min(end);
+        &self.0[start..end]
+    }
+
+    /// Renders the list as a single CSV record, separating elements with commas.
+    ///
+    /// Elements whose rendered form contains a comma, quote, or line break are wrapped
+    /// in double quotes, with embedded quotes doubled as described in RFC 4180.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2, 3].to_csv_line(), "1,2,3");
+    /// assert_eq!(vel!["a", "b,c", "say \"hi\""].to_csv_line(), "a,\"b,c\",\"say \"\"hi\"\"\"");
+    /// ```
+    pub fn to_csv_line(&self) -> String
+    where
+        T: Display,
+    {
+        let mut line = String::new();
+        for (i, item) in self.0.iter().enumerate() {
+            if i > 0 {
+                line.push(',');
+            }
+            let field = alloc::format!("{}", item);
+            if field.contains([',', '"', '\n', '\r']) {
+                line.push('"');
+                line.push_str(&field.replace('"', "\"\""));
+                line.push('"');
+            } else {
+                line.push_str(&field);
+            }
+        }
+        line
+    }
+
+    /// Splits off the last `n` elements, returning `(head, last_n)` as new lists.
+    ///
+    /// If `n` is at least the length, `head` is empty and `last_n` is the whole list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// let v = vel![1, 2, 3, 4];
+    /// assert_eq!(v.split_last_n(1), (vel![1, 2, 3], vel![4]));
+    /// assert_eq!(v.split_last_n(4), (Vel::new(), vel![1, 2, 3, 4]));
+    /// assert_eq!(v.split_last_n(9), (Vel::new(), vel![1, 2, 3, 4]));
+    /// ```
+    pub fn split_last_n(&self, n: usize) -> (Veclite<T>, Veclite<T>)
+    where
+        T: Clone,
+    {
+        let (head, tail) = self.0.split_at(self.0.len().saturating_sub(n));
+        (Veclite::wrap(head.to_vec()), Veclite::wrap(tail.to_vec()))
+    }
+
+    /// Replaces every element equal to `target` with a clone of `replacement`.
+    ///
+    /// Returns the number of elements replaced.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![2, 1, 2, 3, 2];
+    /// assert_eq!(v.replace_all(&2, &9), 3);
+    /// assert_eq!(v, vel![9, 1, 9, 3, 9]);
+    /// ```
+    pub fn replace_all(&mut self, target: &T, replacement: &T) -> usize
+    where
+        T: PartialEq + Clone,
+    {
+        let mut count = 0;
+        for item in self.0.iter_mut().filter(|item| *item == target) {
+            *item = replacement.clone();
+            count += 1;
+        }
+        count
+    }
+
+    /// Returns a `Display` adapter that skips any element equal to the one before it.
+    ///
+    /// Only the output is collapsed; the list itself is left untouched.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 1, 2, 2, 2, 3];
+    /// assert_eq!(format!("{}", v.display_collapsed()), "1 2 3");
+    /// assert_eq!(v.len(), 6);
+    /// ```
+    pub fn display_collapsed(&self) -> impl Display + '_
+    where
+        T: Display + PartialEq,
+    {
+        display_fn(move |f| {
+            let mut prev: Option<&T> = None;
+            for item in &self.0 {
+                if prev == Some(item) {
+                    continue;
+                }
+                if prev.is_some() {
+                    f.write_str(" ")?;
+                }
+                item.fmt(f)?;
+                prev = Some(item);
+            }
+            Ok(())
+        })
+    }
+
+    /// Inserts `value` into an ascending sorted list, keeping it sorted.
+    ///
+    /// The value is placed after any elements equal to it. Returns the index it was
+    /// inserted at.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![1, 3, 5];
+    /// assert_eq!(v.insert_sorted(4), 2);
+    /// assert_eq!(v, vel![1, 3, 4, 5]);
+    /// ```
+    pub fn insert_sorted(&mut self, value: T) -> usize
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(value, T::cmp)
+    }
+
+    /// Inserts `value` into a list sorted according to `cmp`, keeping it sorted.
+    ///
+    /// The value is placed after any elements that compare equal to it. Returns the
+    /// index it was inserted at.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![9, 7, 3, 1];
+    /// let at = v.insert_sorted_by(5, |a, b| b.cmp(a));
+    /// assert_eq!(at, 2);
+    /// assert_eq!(v, vel![9, 7, 5, 3, 1]);
+    /// ```
+    pub fn insert_sorted_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        value: T,
+        mut cmp: F,
+    ) -> usize {
+        let index = self
+            .0
+            .partition_point(|item| cmp(item, &value) != Ordering::Greater);
+        self.0.insert(index, value);
+        index
+    }
+
+    /// Returns a reference to the first element, like `head` in functional languages.
+    ///
+    /// This is the same as [`first`](slice::first).
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2, 3].head(), Some(&1));
+    /// ```
+    pub fn head(&self) -> Option<&T> {
+        self.0.first()
+    }
+
+    /// Returns all but the first element as a new list.
+    ///
+    /// An empty list yields an empty list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// assert_eq!(vel![1, 2, 3].tail(), vel![2, 3]);
+    /// assert_eq!(Vel::<i32>::new().tail(), Vel::new());
+    /// ```
+    pub fn tail(&self) -> Veclite<T>
+    where
+        T: Clone,
+    {
+        Veclite::wrap(self.0.get(1..).unwrap_or_default().to_vec())
+    }
+
+    /// Returns the first `n` elements as a slice, or the whole list if it is shorter.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3];
+    /// assert_eq!(v.first_n(2), &[1, 2]);
+    /// assert_eq!(v.first_n(10), &[1, 2, 3]);
+    /// assert!(v.first_n(0).is_empty());
+    /// ```
+    pub fn first_n(&self, n: usize) -> &[T] {
+        &self.0[..n.min(self.0.len())]
+    }
+
+    /// Returns the last `n` elements as a slice, or the whole list if it is shorter.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3];
+    /// assert_eq!(v.last_n(2), &[2, 3]);
+    /// assert_eq!(v.last_n(10), &[1, 2, 3]);
+    /// assert!(v.last_n(0).is_empty());
+    /// ```
+    pub fn last_n(&self, n: usize) -> &[T] {
+        &self.0[self.0.len().saturating_sub(n)..]
+    }
+
+    /// Groups maximal runs of equal consecutive elements into separate lists.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 1, 2, 3, 3].split_runs(), vel![vel![1, 1], vel![2], vel![3, 3]]);
+    /// assert_eq!(vel![7, 7, 7].split_runs(), vel![vel![7, 7, 7]]);
+    /// assert_eq!(vel![1, 2, 3].split_runs(), vel![vel![1], vel![2], vel![3]]);
+    /// ```
+    pub fn split_runs(&self) -> Veclite<Veclite<T>>
+    where
+        T: Clone + PartialEq,
+    {
+        Veclite::wrap(
+            self.0
+                .chunk_by(|a, b| a == b)
+                .map(|run| Veclite::wrap(run.to_vec()))
+                .collect(),
+        )
+    }
+
+    /// Returns a `Display` adapter that right-pads each element with spaces to at least
+    /// `min_width` characters, separating elements with a single space.
+    ///
+    /// Elements wider than `min_width` are printed as-is.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 22, 333, 4444];
+    /// assert_eq!(format!("{}", v.display_padded(3)), "1   22  333 4444");
+    /// ```
+    pub fn display_padded(&self, min_width: usize) -> impl Display + '_
+    where
+        T: Display,
+    {
+        display_fn(move |f| {
+            for (i, item) in self.0.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(" ")?;
+                }
+                write!(f, "{:<1$}", alloc::format!("{}", item), min_width)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Splits the list into chunks of exactly `size` elements plus the leftover
+    /// remainder, mirroring [`chunks_exact`](slice::chunks_exact).
+    ///
+    /// A `size` of 0 yields no chunks and the whole list as the remainder.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// let (chunks, rest) = vel![1, 2, 3, 4, 5].chunks_exact_vel(2);
+    /// assert_eq!(chunks, vel![vel![1, 2], vel![3, 4]]);
+    /// assert_eq!(rest, vel![5]);
+    ///
+    /// let (chunks, rest) = vel![1, 2].chunks_exact_vel(0);
+    /// assert_eq!(chunks, Vel::new());
+    /// assert_eq!(rest, vel![1, 2]);
+    /// ```
+    pub fn chunks_exact_vel(&self, size: usize) -> (Veclite<Veclite<T>>, Veclite<T>)
+    where
+        T: Clone,
+    {
+        if size == 0 {
+            return (Veclite::new(), Veclite::wrap(self.0.clone()));
+        }
+        let chunks = self.0.chunks_exact(size);
+        let remainder = Veclite::wrap(chunks.remainder().to_vec());
+        (
+            Veclite::wrap(chunks.map(|chunk| Veclite::wrap(chunk.to_vec())).collect()),
+            remainder,
+        )
+    }
+
+    /// Returns an iterator over `(index, &mut element)` pairs.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![0, 0, 0];
+    /// for (i, x) in v.iter_mut_indexed() {
+    ///     *x = i;
+    /// }
+    /// assert_eq!(v, vel![0, 1, 2]);
+    /// ```
+    pub fn iter_mut_indexed(&mut self) -> impl Iterator<Item = (usize, &mut T)> {
+        self.0.iter_mut().enumerate()
+    }
+
+    /// Returns the index of the largest element, or `None` if the list is empty.
+    ///
+    /// On ties the first such index is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 5, 3, 5].position_max(), Some(1));
+    /// ```
+    pub fn position_max(&self) -> Option<usize>
+    where
+        T: Ord,
+    {
+        let mut best: Option<(usize, &T)> = None;
+        for (i, item) in self.0.iter().enumerate() {
+            if best.map_or(true, |(_, b)| item > b) {
+                best = Some((i, item));
+            }
+        }
+        best.map(|(i, _)| i)
+    }
+
+    /// Returns the index of the smallest element, or `None` if the list is empty.
+    ///
+    /// On ties the first such index is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![4, 2, 3, 2].position_min(), Some(1));
+    /// ```
+    pub fn position_min(&self) -> Option<usize>
+    where
+        T: Ord,
+    {
+        let mut best: Option<(usize, &T)> = None;
+        for (i, item) in self.0.iter().enumerate() {
+            if best.map_or(true, |(_, b)| item < b) {
+                best = Some((i, item));
+            }
+        }
+        best.map(|(i, _)| i)
+    }
+
+    /// Splits the list in two at `at`, returning the elements from `at` onward as a new
+    /// list and leaving `self` with the elements before it.
+    ///
+    /// # Panics
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![1, 2, 3, 4];
+    /// let tail = v.split_off(2);
+    /// assert_eq!(v, vel![1, 2]);
+    /// assert_eq!(tail, vel![3, 4]);
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Veclite<T> {
+        Veclite::wrap(self.0.split_off(at))
+    }
+
+    /// Applies `f` to each consecutive chunk of `size` elements and collects the results.
+    ///
+    /// The last chunk may be shorter than `size`. A `size` of 0 yields an empty list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let sums = vel![1, 2, 3, 4].chunk_map(2, |c| c.iter().sum::<i32>());
+    /// assert_eq!(sums, vel![3, 7]);
+    /// ```
+    pub fn chunk_map<U, F: FnMut(&[T]) -> U>(&self, size: usize, f: F) -> Veclite<U> {
+        if size == 0 {
+            return Veclite::new();
+        }
+        Veclite::wrap(self.0.chunks(size).map(f).collect())
+    }
+
+    /// Binary searches a list sorted by the key extracted with `f`.
+    ///
+    /// Returns `Ok(index)` of a matching element, or `Err(index)` where an element with
+    /// that key could be inserted to keep the list sorted. The list must be sorted by
+    /// the key, otherwise the result is unspecified.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let people = vel![("ann", 23), ("bob", 31), ("cy", 47)];
+    /// assert_eq!(people.binary_search_by_key(&31, |&(_, age)| age), Ok(1));
+    /// assert_eq!(people.binary_search_by_key(&40, |&(_, age)| age), Err(2));
+    /// ```
+    pub fn binary_search_by_key<B: Ord, F: FnMut(&T) -> B>(
+        &self,
+        key: &B,
+        f: F,
+    ) -> Result<usize, usize> {
+        self.0.binary_search_by_key(key, f)
+    }
+
+    /// Collapses runs of consecutive equal elements, keeping the last element of each
+    /// run rather than the first as [`dedup`](Vec::dedup) does.
+    ///
+    /// The difference is only observable when `PartialEq` ignores part of the value,
+    /// such as a tag or timestamp field.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    ///
+    /// #[derive(Debug)]
+    /// struct Reading { value: i32, tag: char }
+    ///
+    /// impl PartialEq for Reading {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.value == other.value
+    ///     }
+    /// }
+    ///
+    /// let mut v = vel![
+    ///     Reading { value: 1, tag: 'a' },
+    ///     Reading { value: 1, tag: 'b' },
+    ///     Reading { value: 2, tag: 'c' },
+    ///     Reading { value: 2, tag: 'd' },
+    /// ];
+    /// v.dedup_keep_last();
+    /// let tags: Vec<char> = v.iter().map(|r| r.tag).collect();
+    /// assert_eq!(tags, ['b', 'd']);
+    /// ```
+    pub fn dedup_keep_last(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.0.dedup_by(|later, kept| {
+            if later == kept {
+                core::mem::swap(later, kept);
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    /// Returns a new list with a clone of `sep` placed between each pair of elements.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// assert_eq!(vel![1, 2, 3].intersperse(0), vel![1, 0, 2, 0, 3]);
+    /// assert_eq!(vel![1].intersperse(0), vel![1]);
+    /// assert_eq!(Vel::<i32>::new().intersperse(0), Vel::new());
+    /// ```
+    pub fn intersperse(&self, sep: T) -> Veclite<T>
+    where
+        T: Clone,
+    {
+        let mut out = Vec::with_capacity((self.0.len() * 2).saturating_sub(1));
+        for (i, item) in self.0.iter().enumerate() {
+            if i > 0 {
+                out.push(sep.clone());
+            }
+            out.push(item.clone());
+        }
+        Veclite::wrap(out)
+    }
+
+    /// Returns the longest prefix whose elements all satisfy `pred`, as a new list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2, 3, 1].take_while(|&x| x < 3), vel![1, 2]);
+    /// ```
+    pub fn take_while<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Veclite<T>
+    where
+        T: Clone,
+    {
+        let end = self.0.iter().position(|x| !pred(x)).unwrap_or(self.0.len());
+        Veclite::wrap(self.0[..end].to_vec())
+    }
+
+    /// Returns the elements remaining after the longest prefix satisfying `pred`, as a
+    /// new list. This is the complement of [`take_while`](Self::take_while).
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2, 3, 1].drop_while(|&x| x < 3), vel![3, 1]);
+    /// ```
+    pub fn drop_while<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Veclite<T>
+    where
+        T: Clone,
+    {
+        let start = self.0.iter().position(|x| !pred(x)).unwrap_or(self.0.len());
+        Veclite::wrap(self.0[start..].to_vec())
+    }
+
+    /// Overwrites every element in `range` with a clone of `value`, keeping the length.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds, just like slicing with it would.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![0, 0, 0, 0];
+    /// v.fill_range(1..3, 9);
+    /// assert_eq!(v, vel![0, 9, 9, 0]);
+    /// ```
+    pub fn fill_range<R: RangeBounds<usize>>(&mut self, range: R, value: T)
+    where
+        T: Clone,
+    {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.0[bounds].fill(value);
+    }
+
+    /// Swaps the contents of two lists in O(1), without copying any elements.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut a = vel![1, 2];
+    /// let mut b = vel![3, 4, 5];
+    /// a.swap_contents(&mut b);
+    /// assert_eq!((a.len(), b.len()), (3, 2));
+    /// assert_eq!(a, vel![3, 4, 5]);
+    /// assert_eq!(b, vel![1, 2]);
+    /// ```
+    pub fn swap_contents(&mut self, other: &mut Veclite<T>) {
+        core::mem::swap(&mut self.0, &mut other.0);
+    }
+
+    /// Returns an iterator over each pair of adjacent elements, `(v[i], v[i + 1])`.
+    ///
+    /// Lists with fewer than two elements yield nothing.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 3, 6];
+    /// let deltas: Vec<i32> = v.iter_pairs().map(|(a, b)| b - a).collect();
+    /// assert_eq!(deltas, [2, 3]);
+    /// assert_eq!(vel![1].iter_pairs().count(), 0);
+    /// ```
+    pub fn iter_pairs(&self) -> impl Iterator<Item = (&T, &T)> + '_ {
+        self.0.windows(2).map(|w| (&w[0], &w[1]))
+    }
+
+    /// Run-length encodes the list, collapsing each run of equal consecutive elements
+    /// into a `(value, count)` pair.
+    ///
+    /// [`from_rle`](Self::from_rle) reverses the encoding.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 1, 2].rle(), vel![(1, 2), (2, 1)]);
+    /// ```
+    pub fn rle(&self) -> Veclite<(T, usize)>
+    where
+        T: Clone + PartialEq,
+    {
+        Veclite::wrap(
+            self.0
+                .chunk_by(|a, b| a == b)
+                .map(|run| (run[0].clone(), run.len()))
+                .collect(),
+        )
+    }
+
+    /// Decodes `(value, count)` pairs produced by [`rle`](Self::rle) back into a list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// let v = vel![4, 4, 4, 7, 4];
+    /// assert_eq!(Vel::from_rle(&v.rle()), v);
+    /// ```
+    pub fn from_rle(pairs: &[(T, usize)]) -> Veclite<T>
+    where
+        T: Clone,
+    {
+        let mut out = Vec::with_capacity(pairs.iter().map(|(_, n)| n).sum());
+        for (value, count) in pairs {
+            out.resize(out.len() + count, value.clone());
+        }
+        Veclite::wrap(out)
+    }
+
+    /// Renders the list space-separated in at most `max_chars` characters.
+    ///
+    /// If the whole list does not fit, as many leading elements as possible are kept
+    /// and `…` is appended; the ellipsis counts toward `max_chars`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![10, 20, 30, 40];
+    /// assert_eq!(v.display_within(7), "10 20…");
+    /// assert_eq!(v.display_within(11), "10 20 30 40");
+    ///
+    /// // A budget ending exactly on an element boundary.
+    /// let v = vel![10, 20, 30];
+    /// assert_eq!(v.display_within(8), "10 20 30");
+    /// assert_eq!(v.display_within(6), "10 20…");
+    /// assert_eq!(v.display_within(5), "10…");
+    /// assert_eq!(v.display_within(1), "…");
+    /// assert_eq!(v.display_within(0), "");
+    /// ```
+    pub fn display_within(&self, max_chars: usize) -> String
+    where
+        T: Display,
+    {
+        let mut out = String::new();
+        let mut used = 0;
+        // Byte length of the longest prefix that still leaves room for `…`.
+        let mut cut = 0;
+        for (i, item) in self.0.iter().enumerate() {
+            let field = alloc::format!("{}", item);
+            let sep = usize::from(i > 0);
+            let width = sep + field.chars().count();
+            if used + width > max_chars {
+                out.truncate(cut);
+                if max_chars > 0 {
+                    out.push('…');
+                }
+                return out;
+            }
+            if sep == 1 {
+                out.push(' ');
+            }
+            out.push_str(&field);
+            used += width;
+            if used < max_chars {
+                cut = out.len();
+            }
+        }
+        out
+    }
+
+    /// Returns the elements at indices `offset`, `offset + step`, `offset + 2 * step`, …
+    /// as a new list.
+    ///
+    /// A `step` of 0 yields an empty list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// assert_eq!(vel![0, 1, 2, 3, 4, 5].sample(1, 2), vel![1, 3, 5]);
+    /// assert_eq!(vel![0, 1, 2].sample(0, 0), Vel::new());
+    /// ```
+    pub fn sample(&self, offset: usize, step: usize) -> Veclite<T>
+    where
+        T: Clone,
+    {
+        if step == 0 {
+            return Veclite::new();
+        }
+        Veclite::wrap(self.0.iter().skip(offset).step_by(step).cloned().collect())
+    }
+
+    /// Returns the index of the first element matching `pred`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2, 3, 4].find_index(|x| x % 2 == 0), Some(1));
+    /// assert_eq!(vel![1, 3].find_index(|x| x % 2 == 0), None);
+    /// ```
+    pub fn find_index<F: FnMut(&T) -> bool>(&self, pred: F) -> Option<usize> {
+        self.0.iter().position(pred)
+    }
+
+    /// Returns the index of the last element matching `pred`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2, 3, 4].rfind_index(|x| x % 2 == 0), Some(3));
+    /// ```
+    pub fn rfind_index<F: FnMut(&T) -> bool>(&self, pred: F) -> Option<usize> {
+        self.0.iter().rposition(pred)
+    }
+
+    /// Clamps every element into `[min, max]` in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, or if the bounds cannot be compared (such as a NaN bound),
+    /// like [`f64::clamp`].
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![-5, 0, 5, 10];
+    /// v.clamp_each(0, 7);
+    /// assert_eq!(v, vel![0, 0, 5, 7]);
+    /// ```
+    pub fn clamp_each(&mut self, min: T, max: T)
+    where
+        T: Clone + PartialOrd,
+    {
+        assert!(min <= max, "clamp_each: min must not be greater than max");
+        for item in &mut self.0 {
+            if *item < min {
+                *item = min.clone();
+            } else if *item > max {
+                *item = max.clone();
+            }
+        }
+    }
+
+    /// Returns a `Display` adapter that formats the list using a [`Separator`] preset.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Separator};
+    /// let v = vel![1, 2, 3];
+    /// assert_eq!(format!("{}", v.display_with(&Separator::COMMA)), "1, 2, 3");
+    /// assert_eq!(format!("{}", v.display_with(&Separator::SPACE)), "1 2 3");
+    /// ```
+    pub fn display_with<'a>(&'a self, fmt: &'a Separator) -> impl Display + 'a
+    where
+        T: Display,
+    {
+        self.display_wrapped(fmt.prefix, fmt.item_sep, fmt.suffix)
+    }
+
+    /// Converts the list into a fixed-size array if it holds exactly `N` elements.
+    ///
+    /// On a length mismatch the original list, including its separator, is returned
+    /// unchanged in the `Err`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2, 3].try_into_array::<3>(), Ok([1, 2, 3]));
+    /// assert_eq!(vel![1, 2, 3].try_into_array::<2>(), Err(vel![1, 2, 3]));
+    ///
+    /// let err = vel![1, 2, 3].separator(",").try_into_array::<2>().unwrap_err();
+    /// assert_eq!(format!("{}", err), "1,2,3");
+    /// ```
+    pub fn try_into_array<const N: usize>(self) -> Result<[T; N], Veclite<T>> {
+        let sep = self.1;
+        self.0.try_into().map_err(|v| Veclite(v, sep))
+    }
+
+    /// Appends `value` unless an equal element is already present.
+    ///
+    /// Returns `true` if the value was added.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![1, 2];
+    /// assert!(!v.push_if_absent(2));
+    /// assert!(v.push_if_absent(3));
+    /// assert_eq!(v, vel![1, 2, 3]);
+    /// ```
+    pub fn push_if_absent(&mut self, value: T) -> bool
+    where
+        T: PartialEq,
+    {
+        if self.0.contains(&value) {
+            return false;
+        }
+        self.0.push(value);
+        true
+    }
+
+    /// Prepends `value` unless an equal element is already present.
+    ///
+    /// Returns `true` if the value was added.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![1, 2];
+    /// assert!(!v.prepend_if_absent(1));
+    /// assert!(v.prepend_if_absent(0));
+    /// assert_eq!(v, vel![0, 1, 2]);
+    /// ```
+    pub fn prepend_if_absent(&mut self, value: T) -> bool
+    where
+        T: PartialEq,
+    {
+        if self.0.contains(&value) {
+            return false;
+        }
+        self.prepend(value);
+        true
+    }
+
+    /// Returns the element `n` positions from the end, where `get_from_end(0)` is the
+    /// last element.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3];
+    /// assert_eq!(v.get_from_end(0), Some(&3));
+    /// assert_eq!(v.get_from_end(2), Some(&1));
+    /// assert_eq!(v.get_from_end(3), None);
+    /// ```
+    pub fn get_from_end(&self, n: usize) -> Option<&T> {
+        self.0.iter().nth_back(n)
+    }
+
+    /// Replaces each element with the result of passing it by value through `f`.
+    ///
+    /// The elements are moved through `f` and collected back into the list. This
+    /// typically reuses the existing allocation, but the standard library does not
+    /// guarantee it.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, the panic is propagated and the list is left empty; elements not
+    /// yet passed to `f` are dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![1, 2, 3];
+    /// v.map_in_place(|x| x * 2);
+    /// assert_eq!(v, vel![2, 4, 6]);
+    /// ```
+    pub fn map_in_place<F: FnMut(T) -> T>(&mut self, f: F) {
+        let items = core::mem::take(&mut self.0);
+        self.0 = items.into_iter().map(f).collect();
+    }
+
+    /// Returns the first element, the interior slice, and the last element, or `None`
+    /// if the list has fewer than two elements.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1].ends_and_middle(), None);
+    /// assert_eq!(vel![1, 2].ends_and_middle(), Some((&1, &[][..], &2)));
+    /// assert_eq!(vel![1, 2, 3, 4].ends_and_middle(), Some((&1, &[2, 3][..], &4)));
+    /// ```
+    pub fn ends_and_middle(&self) -> Option<(&T, &[T], &T)> {
+        match self.0.as_slice() {
+            [first, middle @ .., last] => Some((first, middle, last)),
+            _ => None,
+        }
+    }
+
+    /// Keeps only the elements at the positions listed in `keep`, in their original
+    /// order.
+    ///
+    /// Indices in `keep` may appear in any order; out-of-range indices are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel!['a', 'b', 'c', 'd'];
+    /// v.retain_indices(&[2, 0, 9]);
+    /// assert_eq!(v, vel!['a', 'c']);
+    /// ```
+    pub fn retain_indices(&mut self, keep: &[usize]) {
+        let mut mask = alloc::vec![false; self.0.len()];
+        for &i in keep {
+            if let Some(slot) = mask.get_mut(i) {
+                *slot = true;
+            }
+        }
+        let mut mask = mask.into_iter();
+        self.0.retain(|_| mask.next().unwrap_or(false));
+    }
+
+    /// Returns a `Display` adapter that lays the elements out in rows of `cols`
+    /// right-aligned columns, each at least `width` characters wide.
+    ///
+    /// Columns are separated by a single space and rows by a newline. A `cols` of 0
+    /// prints nothing.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3, 4, 5];
+    /// assert_eq!(format!("{}", v.display_table(2, 3)), "  1   2\n  3   4\n  5");
+    /// ```
+    pub fn display_table(&self, cols: usize, width: usize) -> impl Display + '_
+    where
+        T: Display,
+    {
+        display_fn(move |f| {
+            if cols == 0 {
+                return Ok(());
+            }
+            for (r, row) in self.0.chunks(cols).enumerate() {
+                if r > 0 {
+                    f.write_str("\n")?;
+                }
+                for (c, item) in row.iter().enumerate() {
+                    if c > 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "{:>1$}", alloc::format!("{}", item), width)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Merges two ascending sorted lists into a new sorted list in O(n + m).
+    ///
+    /// Both `self` and `other` must already be sorted, otherwise the result is not
+    /// sorted either. On ties, elements from `self` come first.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let merged = vel![1, 3, 5].merge_sorted(&vel![2, 4, 6]);
+    /// assert_eq!(merged, vel![1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn merge_sorted(&self, other: &Veclite<T>) -> Veclite<T>
+    where
+        T: Ord + Clone,
+    {
+        let mut out = Vec::with_capacity(self.0.len() + other.0.len());
+        let (mut a, mut b) = (self.0.iter().peekable(), other.0.iter().peekable());
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            if y < x {
+                out.push((*y).clone());
+                b.next();
+            } else {
+                out.push((*x).clone());
+                a.next();
+            }
+        }
+        out.extend(a.cloned());
+        out.extend(b.cloned());
+        Veclite::wrap(out)
+    }
+
+    /// Returns the elements of `self` that do not appear anywhere in `other`.
+    ///
+    /// Order and duplicates from `self` are preserved. This is O(n * m).
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2, 2, 3].difference(&vel![2, 4]), vel![1, 3]);
+    /// ```
+    pub fn difference(&self, other: &Veclite<T>) -> Veclite<T>
+    where
+        T: PartialEq + Clone,
+    {
+        Veclite::wrap(
+            self.0
+                .iter()
+                .filter(|x| !other.0.contains(x))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Returns the elements of `self` that also appear somewhere in `other`.
+    ///
+    /// Order and duplicates from `self` are preserved. This is O(n * m).
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2, 2, 3].intersection(&vel![2, 4]), vel![2, 2]);
+    /// ```
+    pub fn intersection(&self, other: &Veclite<T>) -> Veclite<T>
+    where
+        T: PartialEq + Clone,
+    {
+        Veclite::wrap(
+            self.0
+                .iter()
+                .filter(|x| other.0.contains(x))
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Appends clones of `fill` until the list is `len` elements long.
+    ///
+    /// Does nothing if the list is already at least `len` long; it never truncates.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![1, 2];
+    /// v.pad_to(4, 0);
+    /// assert_eq!(v, vel![1, 2, 0, 0]);
+    /// v.pad_to(1, 0);
+    /// assert_eq!(v, vel![1, 2, 0, 0]);
+    /// ```
+    pub fn pad_to(&mut self, len: usize, fill: T)
+    where
+        T: Clone,
+    {
+        if len > self.0.len() {
+            self.0.resize(len, fill);
+        }
+    }
+
+    /// Prepends clones of `fill` until the list is `len` elements long.
+    ///
+    /// Does nothing if the list is already at least `len` long. Existing elements are
+    /// shifted only once.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![3, 4];
+    /// v.pad_front_to(4, 0);
+    /// assert_eq!(v, vel![0, 0, 3, 4]);
+    /// ```
+    pub fn pad_front_to(&mut self, len: usize, fill: T)
+    where
+        T: Clone,
+    {
+        let missing = len.saturating_sub(self.0.len());
+        if missing > 0 {
+            self.0.resize(len, fill);
+            self.0.rotate_right(missing);
+        }
+    }
+
+    /// Returns an iterator that starts at `start` and wraps around, visiting every
+    /// element exactly once.
+    ///
+    /// A `start` past the end wraps modulo the length.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel!['a', 'b', 'c', 'd'];
+    /// let order: Vec<char> = v.iter_from(2).copied().collect();
+    /// assert_eq!(order, ['c', 'd', 'a', 'b']);
+    /// assert_eq!(v.iter_from(5).next(), Some(&'b'));
+    /// ```
+    pub fn iter_from(&self, start: usize) -> impl Iterator<Item = &T> + '_ {
+        let split = if self.0.is_empty() {
+            0
+        } else {
+            start % self.0.len()
+        };
+        let (front, back) = self.0.split_at(split);
+        back.iter().chain(front)
+    }
+
+    /// Returns a new list with consecutive elements collapsed whenever `same` deems them
+    /// equal, keeping the first of each group.
+    ///
+    /// `same(next, kept)` is called with the next candidate and the most recently kept
+    /// element, in the same order as [`Vec::dedup_by`].
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 5, 6, 10];
+    /// assert_eq!(v.dedup_by_clone(|a: &i32, b| a.abs_diff(*b) <= 1), vel![1, 5, 10]);
+    ///
+    /// // Drop any element not larger than the last one kept.
+    /// let v = vel![1, 3, 2, 5, 4];
+    /// assert_eq!(v.dedup_by_clone(|next, kept| next <= kept), vel![1, 3, 5]);
+    /// ```
+    pub fn dedup_by_clone<F: FnMut(&T, &T) -> bool>(&self, mut same: F) -> Veclite<T>
+    where
+        T: Clone,
+    {
+        let mut out: Vec<T> = Vec::new();
+        for item in &self.0 {
+            if out.last().is_some_and(|kept| same(item, kept)) {
+                continue;
+            }
+            out.push(item.clone());
+        }
+        Veclite::wrap(out)
+    }
+
+    /// Returns `(index, value)` pairs for every element matching `pred`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![10, 21, 32, 43];
+    /// assert_eq!(v.enumerate_filter(|x| x % 2 == 0), vel![(0, 10), (2, 32)]);
+    /// ```
+    pub fn enumerate_filter<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Veclite<(usize, T)>
+    where
+        T: Clone,
+    {
+        Veclite::wrap(
+            self.0
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| pred(x))
+                .map(|(i, x)| (i, x.clone()))
+                .collect(),
+        )
+    }
+
+    /// Returns a `Display` adapter that prints the list as a compact JSON-style array,
+    /// such as `[1,2,3]`.
+    ///
+    /// Each element's `Display` output is written verbatim and assumed to already be
+    /// valid JSON. String elements are not quoted or escaped; wrap them yourself first.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(format!("{}", vel![1, 2, 3].display_json()), "[1,2,3]");
+    /// assert_eq!(format!("{}", vel!["\"a\""].display_json()), "[\"a\"]");
+    /// ```
+    pub fn display_json(&self) -> impl Display + '_
+    where
+        T: Display,
+    {
+        self.display_wrapped("[", ",", "]")
+    }
+
+    /// Splits the list into segments separated by elements matching `is_sep`.
+    ///
+    /// Separators are dropped. Like [`slice::split`], adjacent separators or a
+    /// separator at either end produce empty segments.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 0, 2, 0, 3];
+    /// assert_eq!(v.split_on(|&x| x == 0), vel![vel![1], vel![2], vel![3]]);
+    /// ```
+    pub fn split_on<F: FnMut(&T) -> bool>(&self, is_sep: F) -> Veclite<Veclite<T>>
+    where
+        T: Clone,
+    {
+        Veclite::wrap(
+            self.0
+                .split(is_sep)
+                .map(|part| Veclite::wrap(part.to_vec()))
+                .collect(),
+        )
+    }
+
+    /// Splits the list into segments separated by elements matching `is_sep`, scanning
+    /// from the end.
+    ///
+    /// The segments are the same as those of [`split_on`](Self::split_on), in reverse
+    /// order.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 0, 2, 0, 3];
+    /// assert_eq!(v.rsplit_on(|&x| x == 0), vel![vel![3], vel![2], vel![1]]);
+    ///
+    /// let mut forward = v.split_on(|&x| x == 0);
+    /// forward.reverse();
+    /// assert_eq!(v.rsplit_on(|&x| x == 0), forward);
+    /// ```
+    pub fn rsplit_on<F: FnMut(&T) -> bool>(&self, is_sep: F) -> Veclite<Veclite<T>>
+    where
+        T: Clone,
+    {
+        Veclite::wrap(
+            self.0
+                .rsplit(is_sep)
+                .map(|part| Veclite::wrap(part.to_vec()))
+                .collect(),
+        )
+    }
+
+    /// Returns the length of the longest run of equal consecutive elements, or 0 for an
+    /// empty list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// assert_eq!(vel![1, 1, 2, 2, 2, 1].longest_run(), 3);
+    /// assert_eq!(Vel::<i32>::new().longest_run(), 0);
+    /// ```
+    pub fn longest_run(&self) -> usize
+    where
+        T: PartialEq,
+    {
+        self.0
+            .chunk_by(|a, b| a == b)
+            .map(<[T]>::len)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if the list begins with `prefix`. An empty prefix always matches.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3, 4];
+    /// assert!(v.starts_with_slice(&[1, 2]));
+    /// assert!(v.starts_with_slice(&[]));
+    /// assert!(!v.starts_with_slice(&[2]));
+    /// ```
+    pub fn starts_with_slice(&self, prefix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.0.starts_with(prefix)
+    }
+
+    /// Returns `true` if the list ends with `suffix`. An empty suffix always matches.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3, 4];
+    /// assert!(v.ends_with_slice(&[3, 4]));
+    /// assert!(!v.ends_with_slice(&[2, 4]));
+    /// ```
+    pub fn ends_with_slice(&self, suffix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        self.0.ends_with(suffix)
+    }
+
+    /// Returns a new list with matching elements removed from both ends.
+    ///
+    /// Trimming stops at the first non-matching element on each side, so matches in
+    /// the middle are kept.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![0, 0, 1, 2, 0, 3, 0, 0];
+    /// assert_eq!(v.trim_matches(|&x| x == 0), vel![1, 2, 0, 3]);
+    /// ```
+    pub fn trim_matches<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Veclite<T>
+    where
+        T: Clone,
+    {
+        let start = match self.0.iter().position(|x| !pred(x)) {
+            Some(start) => start,
+            None => return Veclite::new(),
+        };
+        let end = self
+            .0
+            .iter()
+            .rposition(|x| !pred(x))
+            .map_or(start, |i| i + 1);
+        Veclite::wrap(self.0[start..end].to_vec())
+    }
+
+    /// Removes the element at `index` in O(1) by replacing it with the last element,
+    /// returning `None` instead of panicking when `index` is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![1, 2, 3];
+    /// assert_eq!(v.try_swap_remove(0), Some(1));
+    /// assert_eq!(v, vel![3, 2]);
+    /// assert_eq!(v.try_swap_remove(5), None);
+    /// ```
+    pub fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+        if index < self.0.len() {
+            Some(self.0.swap_remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Buckets every element by the key returned from `key`, keeping each bucket in
+    /// the original order. Requires the `std` feature.
+    ///
+    /// Unlike run-based grouping, equal keys are gathered from anywhere in the list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let groups = vel![1, 2, 3, 4, 5].group_by(|x| x % 2 == 0);
+    /// assert_eq!(groups[&true], vel![2, 4]);
+    /// assert_eq!(groups[&false], vel![1, 3, 5]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn group_by<K: Eq + Hash, F: FnMut(&T) -> K>(
+        &self,
+        mut key: F,
+    ) -> std::collections::HashMap<K, Veclite<T>>
+    where
+        T: Clone,
+    {
+        let mut groups = std::collections::HashMap::new();
+        for item in &self.0 {
+            groups
+                .entry(key(item))
+                .or_insert_with(Veclite::new)
+                .push(item.clone());
+        }
+        groups
+    }
+
+    /// Combines clones of the elements left to right with `f`, using the first element
+    /// as the starting value. Returns `None` for an empty list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// assert_eq!(vel![1, 2, 3, 4].reduce(|a, b| a + b), Some(10));
+    /// assert_eq!(Vel::<i32>::new().reduce(|a, b| a + b), None);
+    /// ```
+    pub fn reduce<F: FnMut(T, T) -> T>(&self, f: F) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.0.iter().cloned().reduce(f)
+    }
+
+    /// Folds every element into an accumulator, starting from `init`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let s = vel![1, 2, 3].fold(String::new(), |mut acc, x| {
+    ///     acc.push_str(&x.to_string());
+    ///     acc
+    /// });
+    /// assert_eq!(s, "123");
+    /// ```
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.0.iter().fold(init, f)
+    }
+
+    /// Returns the index of every element equal to `value`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![2, 1, 2, 3, 2].positions_of(&2), vel![0, 2, 4]);
+    /// ```
+    pub fn positions_of(&self, value: &T) -> Veclite<usize>
+    where
+        T: PartialEq,
+    {
+        Veclite::wrap(
+            self.0
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| *x == value)
+                .map(|(i, _)| i)
+                .collect(),
+        )
+    }
+
+    /// Divides the list into `parts` consecutive sublists whose sizes differ by at most
+    /// one, with earlier parts taking the extra elements.
+    ///
+    /// When `parts` exceeds the length, the trailing parts are empty. A `parts` of 0
+    /// yields an empty outer list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// assert_eq!(vel![1, 2, 3, 4, 5].split_into(2), vel![vel![1, 2, 3], vel![4, 5]]);
+    /// assert_eq!(vel![1, 2].split_into(3), vel![vel![1], vel![2], Vel::new()]);
+    /// assert_eq!(vel![1, 2].split_into(0), Vel::new());
+    /// ```
+    pub fn split_into(&self, parts: usize) -> Veclite<Veclite<T>>
+    where
+        T: Clone,
+    {
+        if parts == 0 {
+            return Veclite::new();
+        }
+        let (base, extra) = (self.0.len() / parts, self.0.len() % parts);
+        let mut rest = self.0.as_slice();
+        let mut out = Vec::with_capacity(parts);
+        for i in 0..parts {
+            let (part, tail) = rest.split_at(base + usize::from(i < extra));
+            out.push(Veclite::wrap(part.to_vec()));
+            rest = tail;
+        }
+        Veclite::wrap(out)
+    }
+
+    /// Moves the list into an [`Rc`](alloc::rc::Rc) so it can be shared by cloning the
+    /// handle instead of the data.
+    ///
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    /// use veclite::vel;
+    /// let shared = vel![1, 2, 3].into_shared();
+    /// let other = Rc::clone(&shared);
+    /// assert_eq!(Rc::strong_count(&shared), 2);
+    /// assert_eq!(*other, vel![1, 2, 3]);
+    /// ```
+    pub fn into_shared(self) -> alloc::rc::Rc<Veclite<T>> {
+        alloc::rc::Rc::new(self)
+    }
+
+    /// Moves the list into an [`Arc`](alloc::sync::Arc) so it can be shared across
+    /// threads by cloning the handle. Requires the `arc` feature.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use veclite::vel;
+    /// let shared = vel![1, 2, 3].into_shared_arc();
+    /// let other = Arc::clone(&shared);
+    /// assert_eq!(Arc::strong_count(&shared), 2);
+    /// assert_eq!(*other, vel![1, 2, 3]);
+    /// ```
+    #[cfg(feature = "arc")]
+    pub fn into_shared_arc(self) -> alloc::sync::Arc<Veclite<T>> {
+        alloc::sync::Arc::new(self)
+    }
+
+    /// Returns a `Display` adapter that prints the list in pages of up to `per_page`
+    /// elements, one labelled line per page starting from `Page 0`.
+    ///
+    /// A `per_page` of 0 prints nothing.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3, 4, 5];
+    /// assert_eq!(
+    ///     format!("{}", v.display_paged(2)),
+    ///     "Page 0: 1 2\nPage 1: 3 4\nPage 2: 5"
+    /// );
+    /// ```
+    pub fn display_paged(&self, per_page: usize) -> impl Display + '_
+    where
+        T: Display,
+    {
+        display_fn(move |f| {
+            if per_page == 0 {
+                return Ok(());
+            }
+            for (i, page) in self.0.chunks(per_page).enumerate() {
+                if i > 0 {
+                    f.write_str("\n")?;
+                }
+                write!(f, "Page {}: ", i)?;
+                write_joined(f, page, " ")?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the first element that is equal to some earlier element, or `None` if all
+    /// elements are distinct.
+    ///
+    /// This is O(n²), since only `PartialEq` is required.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2, 3, 2].first_duplicate(), Some(&2));
+    /// assert_eq!(vel![1, 2, 3].first_duplicate(), None);
+    /// ```
+    pub fn first_duplicate(&self) -> Option<&T>
+    where
+        T: PartialEq,
+    {
+        self.0
+            .iter()
+            .enumerate()
+            .find(|&(i, x)| self.0[..i].contains(x))
+            .map(|(_, x)| x)
+    }
+
+    /// Returns `true` if the list reads the same forwards and backwards.
+    ///
+    /// Empty and single-element lists are palindromes.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert!(vel![1, 2, 1].is_palindrome());
+    /// assert!(!vel![1, 2, 3].is_palindrome());
+    /// ```
+    pub fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let half = self.0.len() / 2;
+        self.0.iter().take(half).eq(self.0.iter().rev().take(half))
+    }
+
+    /// Returns a `Display` adapter that prints every cyclic rotation of the list, one
+    /// per line, starting with the list itself.
+    ///
+    /// An empty list prints nothing.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3];
+    /// assert_eq!(format!("{}", v.display_rotations()), "1 2 3\n2 3 1\n3 1 2");
+    /// ```
+    pub fn display_rotations(&self) -> impl Display + '_
+    where
+        T: Display,
+    {
+        display_fn(move |f| {
+            for start in 0..self.0.len() {
+                if start > 0 {
+                    f.write_str("\n")?;
+                }
+                for (i, item) in self.iter_from(start).enumerate() {
+                    if i > 0 {
+                        f.write_str(" ")?;
+                    }
+                    item.fmt(f)?;
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns a `Display` adapter that prints the first `head` and last `tail`
+    /// elements with `...` between them.
+    ///
+    /// If the list has no more than `head + tail` elements, it prints in full.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// let v: Vel<i32> = (1..=10).collect::<Vec<_>>().into();
+    /// assert_eq!(format!("{}", v.display_head_tail(2, 2)), "1 2 ... 9 10");
+    /// assert_eq!(format!("{}", vel![1, 2, 3].display_head_tail(2, 2)), "1 2 3");
+    /// ```
+    pub fn display_head_tail(&self, head: usize, tail: usize) -> impl Display + '_
+    where
+        T: Display,
+    {
+        display_fn(move |f| {
+            if self.0.len() <= head.saturating_add(tail) {
+                return write_joined(f, &self.0, " ");
+            }
+            write_joined(f, &self.0[..head], " ")?;
+            f.write_str(if head > 0 { " ..." } else { "..." })?;
+            if tail > 0 {
+                f.write_str(" ")?;
+                write_joined(f, self.last_n(tail), " ")?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Returns the running combination of the elements under `f`, the same length as
+    /// the list.
+    ///
+    /// The first element is kept as-is; each later output is `f(previous_output, x)`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![1, 2, 3, 4];
+    /// assert_eq!(v.accumulate(|a, b| a + b), vel![1, 3, 6, 10]);
+    /// assert_eq!(vel![3, 1, 4, 1, 5].accumulate(|a, b| *a.max(b)), vel![3, 3, 4, 4, 5]);
+    /// ```
+    pub fn accumulate<F: FnMut(&T, &T) -> T>(&self, mut f: F) -> Veclite<T>
+    where
+        T: Clone,
+    {
+        let mut out: Vec<T> = Vec::with_capacity(self.0.len());
+        for item in &self.0 {
+            let next = match out.last() {
+                Some(acc) => f(acc, item),
+                None => item.clone(),
+            };
+            out.push(next);
+        }
+        Veclite::wrap(out)
+    }
+
+    /// Returns a sorted copy of the list with duplicates removed, leaving the original
+    /// untouched. This is O(n log n).
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![3, 1, 2, 3, 1];
+    /// assert_eq!(v.sorted_unique(), vel![1, 2, 3]);
+    /// assert_eq!(v, vel![3, 1, 2, 3, 1]);
+    /// ```
+    pub fn sorted_unique(&self) -> Veclite<T>
+    where
+        T: Ord + Clone,
+    {
+        let mut out = self.0.clone();
+        out.sort_unstable();
+        out.dedup();
+        Veclite::wrap(out)
+    }
+
+    /// Folds every element into an accumulator with a fallible `f`, stopping at the
+    /// first error.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let sum_positive = |acc: i32, &x: &i32| if x < 0 { Err(x) } else { Ok(acc + x) };
+    /// assert_eq!(vel![1, 2, 3].try_fold(0, sum_positive), Ok(6));
+    /// assert_eq!(vel![1, -2, 3].try_fold(0, sum_positive), Err(-2));
+    /// ```
+    pub fn try_fold<B, E, F: FnMut(B, &T) -> Result<B, E>>(&self, init: B, f: F) -> Result<B, E> {
+        self.0.iter().try_fold(init, f)
+    }
+
+    /// Applies `f` to each sliding window of `size` elements and collects the results.
+    ///
+    /// If `size` is 0 or larger than the list, the result is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// let sums = vel![1, 2, 3, 4].windows_map(2, |w| w.iter().sum::<i32>());
+    /// assert_eq!(sums, vel![3, 5, 7]);
+    /// assert_eq!(vel![1, 2].windows_map(3, |w| w.len()), Vel::new());
+    /// ```
+    pub fn windows_map<U, F: FnMut(&[T]) -> U>(&self, size: usize, f: F) -> Veclite<U> {
+        if size == 0 {
+            return Veclite::new();
+        }
+        Veclite::wrap(self.0.windows(size).map(f).collect())
+    }
+
+    /// Deinterleaves the list into `(elements at even indices, elements at odd indices)`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![0, 1, 2, 3, 4].split_even_odd(), (vel![0, 2, 4], vel![1, 3]));
+    /// ```
+    pub fn split_even_odd(&self) -> (Veclite<T>, Veclite<T>)
+    where
+        T: Clone,
+    {
+        (self.sample(0, 2), self.sample(1, 2))
+    }
+
+    /// Copies `items` to the front of the list, shifting the existing elements only
+    /// once rather than once per item as repeated [`prepend`](Self::prepend) would.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![4, 5];
+    /// v.prepend_copy_slice(&[1, 2, 3]);
+    /// assert_eq!(v, vel![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn prepend_copy_slice(&mut self, items: &[T])
+    where
+        T: Copy,
+    {
+        self.0.splice(0..0, items.iter().copied());
+    }
+
+    /// Removes all elements and releases the backing allocation.
+    ///
+    /// Unlike `clear`, which keeps the capacity for reuse, this leaves the list with a
+    /// capacity of zero.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![1, 2, 3];
+    /// v.clear_and_shrink();
+    /// assert_eq!(v.len(), 0);
+    /// assert_eq!(v.capacity(), 0);
+    /// ```
+    pub fn clear_and_shrink(&mut self) {
+        self.0 = Vec::new();
+    }
+
+    /// Returns a `Display` adapter that prints one `index: value` line per element,
+    /// numbering from `start`.
+    ///
+    /// Numbers past `usize::MAX` wrap around to 0.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel!["a", "b"];
+    /// assert_eq!(format!("{}", v.display_numbered(1)), "1: a\n2: b");
+    ///
+    /// let expected = format!("{}: a\n0: b", usize::MAX);
+    /// assert_eq!(format!("{}", v.display_numbered(usize::MAX)), expected);
+    /// ```
+    pub fn display_numbered(&self, start: usize) -> impl Display + '_
+    where
+        T: Display,
+    {
+        display_fn(move |f| {
+            for (i, item) in self.0.iter().enumerate() {
+                if i > 0 {
+                    f.write_str("\n")?;
+                }
+                write!(f, "{}: {}", start.wrapping_add(i), item)?;
+            }
+            Ok(())
+        })
+    }
 
-impl<T> Veclite<T> {
-    /// Creates a new, empty `Veclite<T>`.
+    /// Replaces the first element equal to `target` with `replacement`.
+    ///
+    /// Returns `true` if an element was replaced.
     ///
     /// # Example
     /// ```
-    /// use veclite::Vel;
-    /// let v: Vel<i32> = Vel::new();
-    /// assert!(v.is_empty());
+    /// use veclite::vel;
+    /// let mut v = vel![2, 1, 2];
+    /// assert!(v.replace_first(&2, 9));
+    /// assert_eq!(v, vel![9, 1, 2]);
+    /// assert!(!v.replace_first(&5, 0));
     /// ```
-    pub fn new() -> Self {
-        Veclite(Vec::new())
+    pub fn replace_first(&mut self, target: &T, replacement: T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.0.iter_mut().find(|x| *x == target) {
+            Some(slot) => {
+                *slot = replacement;
+                true
+            }
+            None => false,
+        }
     }
 
-    /// Prepends a value to the front of the list.
+    /// Replaces the last element equal to `target` with `replacement`.
+    ///
+    /// Returns `true` if an element was replaced.
     ///
     /// # Example
     /// ```
-    /// use veclite::Vel;
-    /// let mut v = Vel::new();
-    /// v.push(2);
-    /// v.prepend(1);
-    /// assert_eq!(format!("{}", v), "1 2");
+    /// use veclite::vel;
+    /// let mut v = vel![2, 1, 2];
+    /// assert!(v.replace_last(&2, 9));
+    /// assert_eq!(v, vel![2, 1, 9]);
     /// ```
-    pub fn prepend(&mut self, value: T) {
-        self.0.insert(0, value);
+    pub fn replace_last(&mut self, target: &T, replacement: T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.0.iter_mut().rev().find(|x| *x == target) {
+            Some(slot) => {
+                *slot = replacement;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Converts the list into a [`VecDeque`] for O(1) operations at both ends.
+    ///
+    /// This reuses the existing allocation.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// let mut d = vel![2, 3].into_vec_deque();
+    /// d.push_front(1);
+    /// assert_eq!(Vel::from(d), vel![1, 2, 3]);
+    /// ```
+    pub fn into_vec_deque(self) -> VecDeque<T> {
+        self.0.into()
+    }
+
+    /// Maps each element with `f` and sums the results.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel!["ab", "cde"].sum_by(|s| s.len()), 5);
+    /// ```
+    pub fn sum_by<S: Sum, F: FnMut(&T) -> S>(&self, f: F) -> S {
+        self.0.iter().map(f).sum()
+    }
+
+    /// Returns an iterator over `(index, &element)` pairs from the last element to the
+    /// first, keeping each element's original index.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel!['a', 'b', 'c'];
+    /// let pairs: Vec<_> = v.iter_rev_indexed().collect();
+    /// assert_eq!(pairs, [(2, &'c'), (1, &'b'), (0, &'a')]);
+    /// ```
+    pub fn iter_rev_indexed(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.0.iter().enumerate().rev()
+    }
+
+    /// Counts the elements for which `pred(index, &element)` returns `true`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![0, 5, 2, 9];
+    /// assert_eq!(v.count_where_indexed(|i, &x| x == i), 2);
+    /// ```
+    pub fn count_where_indexed<F: FnMut(usize, &T) -> bool>(&self, mut pred: F) -> usize {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|&(i, x)| pred(i, x))
+            .count()
+    }
+
+    /// Returns a `Display` adapter that prints each element followed by `sep`,
+    /// including after the last one.
+    ///
+    /// An empty list prints nothing.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// assert_eq!(format!("{}", vel![1, 2, 3].display_trailing(",")), "1,2,3,");
+    /// assert_eq!(format!("{}", Vel::<i32>::new().display_trailing(",")), "");
+    /// ```
+    pub fn display_trailing<'a>(&'a self, sep: &'a str) -> impl Display + 'a
+    where
+        T: Display,
+    {
+        display_fn(move |f| {
+            for item in &self.0 {
+                item.fmt(f)?;
+                f.write_str(sep)?;
+            }
+            Ok(())
+        })
     }
 }
 
 impl<T: Display> Display for Veclite<T> {
-    /// Formats the list with space-separated elements.
+    /// Formats the list with elements separated by a space, or by the separator set
+    /// with [`separator`](Veclite::separator).
     ///
     /// # Example
     /// ```
@@ -71,14 +2341,50 @@ impl<T: Display> Display for Veclite<T> {
     /// assert_eq!(format!("{}", v), "1 2 3");
     /// ```
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        for (i, item) in self.0.iter().enumerate() {
-            if i > 0 {
-                write!(f, " ")?;
-            }
-            item.fmt(f)?;
+        write_joined(f, &self.0, self.1)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Veclite<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Veclite").field(&self.0).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Veclite<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Default for Veclite<T> {
+    fn default() -> Self {
+        Veclite::new()
+    }
+}
+
+/// `Display` adapter returned by the `display_*` helpers, formatting through a closure.
+struct DisplayFn<F>(F);
+
+impl<F: Fn(&mut Formatter<'_>) -> fmt::Result> Display for DisplayFn<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+fn display_fn<F: Fn(&mut Formatter<'_>) -> fmt::Result>(f: F) -> DisplayFn<F> {
+    DisplayFn(f)
+}
+
+/// Writes `items` separated by `sep`, using each element's own formatting.
+fn write_joined<T: Display>(f: &mut Formatter<'_>, items: &[T], sep: &str) -> fmt::Result {
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            f.write_str(sep)?;
         }
-        Ok(())
+        item.fmt(f)?;
     }
+    Ok(())
 }
 
 impl<T> Deref for Veclite<T> {
@@ -103,7 +2409,7 @@ impl<T> From<Vec<T>> for Veclite<T> {
     /// let v = Vel::from(vec![1, 2, 3]);
     /// ```
     fn from(v: Vec<T>) -> Self {
-        Veclite(v)
+        Veclite::wrap(v)
     }
 }
 
@@ -131,6 +2437,501 @@ impl<'a, T> IntoIterator for &'a mut Veclite<T> {
     }
 }
 
+impl<T> Add for Veclite<T> {
+    type Output = Veclite<T>;
+
+    /// Concatenates two lists, with `rhs`'s elements after `self`'s.
+    ///
+    /// The result keeps the separator of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![1, 2] + vel![3, 4], vel![1, 2, 3, 4]);
+    ///
+    /// let v = vel![1, 2].separator(",") + vel![3].separator(";");
+    /// assert_eq!(format!("{}", v), "1,2,3");
+    /// ```
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.0.extend(rhs.0);
+        self
+    }
+}
+
+impl<T> AddAssign for Veclite<T> {
+    /// Appends `rhs`'s elements to the end of the list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let mut v = vel![1, 2].separator("-");
+    /// v += vel![3, 4];
+    /// assert_eq!(v, vel![1, 2, 3, 4]);
+    /// assert_eq!(format!("{}", v), "1-2-3-4");
+    /// ```
+    fn add_assign(&mut self, rhs: Self) {
+        self.0.extend(rhs.0);
+    }
+}
+
+impl<T: Clone> Mul<usize> for Veclite<T> {
+    type Output = Veclite<T>;
+
+    /// Repeats the list `rhs` times; multiplying by 0 yields an empty list.
+    ///
+    /// The result keeps the separator of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `capacity overflow` if the resulting length overflows `usize`, like
+    /// [`slice::repeat`].
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// assert_eq!(vel![1, 2] * 3, vel![1, 2, 1, 2, 1, 2]);
+    /// assert_eq!(vel![1, 2] * 1, vel![1, 2]);
+    /// assert_eq!(vel![1, 2] * 0, Vel::new());
+    /// assert_eq!(format!("{}", vel![1, 2].separator(",") * 2), "1,2,1,2");
+    /// ```
+    fn mul(self, rhs: usize) -> Self::Output {
+        let mut out = Vec::with_capacity(self.0.len().checked_mul(rhs).expect("capacity overflow"));
+        for _ in 0..rhs {
+            out.extend_from_slice(&self.0);
+        }
+        Veclite(out, self.1)
+    }
+}
+
+impl From<&str> for Veclite<char> {
+    /// Collects the characters of a string slice into a list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// let v = Vel::from("abc");
+    /// assert_eq!(v, vel!['a', 'b', 'c']);
+    /// ```
+    fn from(s: &str) -> Self {
+        Veclite::wrap(s.chars().collect())
+    }
+}
+
+impl<T> From<VecDeque<T>> for Veclite<T> {
+    /// Converts a `VecDeque<T>` into a `Veclite<T>`, preserving front-to-back order.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use veclite::{vel, Vel};
+    /// let d: VecDeque<i32> = (1..=3).collect();
+    /// assert_eq!(Vel::from(d), vel![1, 2, 3]);
+    /// ```
+    fn from(d: VecDeque<T>) -> Self {
+        Veclite::wrap(d.into())
+    }
+}
+
+impl Veclite<u8> {
+    /// Returns a `Display` adapter that prints the bytes as lowercase hex pairs with no
+    /// separator.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// let v: Vel<u8> = vel![0xde, 0xad, 0x0f];
+    /// assert_eq!(format!("{}", v.display_hex()), "dead0f");
+    /// assert_eq!(format!("{}", Vel::<u8>::new().display_hex()), "");
+    /// ```
+    pub fn display_hex(&self) -> impl Display + '_ {
+        display_fn(move |f| self.0.iter().try_for_each(|b| write!(f, "{:02x}", b)))
+    }
+
+    /// Returns a `Display` adapter that prints the bytes as uppercase hex pairs with no
+    /// separator.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::{vel, Vel};
+    /// let v: Vel<u8> = vel![0xde, 0xad, 0x0f];
+    /// assert_eq!(format!("{}", v.display_hex_upper()), "DEAD0F");
+    /// assert_eq!(format!("{}", Vel::<u8>::new().display_hex_upper()), "");
+    /// ```
+    pub fn display_hex_upper(&self) -> impl Display + '_ {
+        display_fn(move |f| self.0.iter().try_for_each(|b| write!(f, "{:02X}", b)))
+    }
+}
+
+macro_rules! impl_float_helpers {
+    ($($t:ty),*) => {$(
+        impl Veclite<$t> {
+            /// Returns the arithmetic mean of the elements, or `None` if the list is empty.
+            ///
+            /// # Example
+            /// ```
+            /// use veclite::{vel, Vel};
+            #[doc = concat!("let v: Vel<", stringify!($t), "> = vel![1.0, 2.0, 3.0];")]
+            /// assert_eq!(v.mean(), Some(2.0));
+            /// ```
+            pub fn mean(&self) -> Option<$t> {
+                if self.0.is_empty() {
+                    return None;
+                }
+                Some(self.0.iter().sum::<$t>() / self.0.len() as $t)
+            }
+
+            /// Returns the population variance of the elements, or `None` if the list is
+            /// empty.
+            ///
+            /// # Example
+            /// ```
+            /// use veclite::{vel, Vel};
+            #[doc = concat!("let v: Vel<", stringify!($t), "> = vel![1.0, 2.0, 3.0];")]
+            /// assert!((v.variance().unwrap() - 2.0 / 3.0).abs() < 1e-6);
+            /// ```
+            pub fn variance(&self) -> Option<$t> {
+                let mean = self.mean()?;
+                let sum_sq: $t = self.0.iter().map(|x| (x - mean) * (x - mean)).sum();
+                Some(sum_sq / self.0.len() as $t)
+            }
+
+            /// Returns a `Display` adapter that prints every element with exactly
+            /// `decimals` fractional digits, space-separated.
+            ///
+            /// # Example
+            /// ```
+            /// use veclite::{vel, Vel};
+            #[doc = concat!("let v: Vel<", stringify!($t), "> = vel![1.5, 2.25];")]
+            /// assert_eq!(format!("{}", v.display_fixed(2)), "1.50 2.25");
+            /// ```
+            pub fn display_fixed(&self, decimals: usize) -> impl Display + '_ {
+                display_fn(move |f| {
+                    for (i, x) in self.0.iter().enumerate() {
+                        if i > 0 {
+                            f.write_str(" ")?;
+                        }
+                        write!(f, "{:.*}", decimals, x)?;
+                    }
+                    Ok(())
+                })
+            }
+        }
+    )*};
+}
+
+impl_float_helpers!(f32, f64);
+
+impl Veclite<String> {
+    /// Returns a new list with every string converted to uppercase.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel!["a".to_string(), "Bc".to_string()];
+    /// assert_eq!(v.to_uppercase(), vel!["A".to_string(), "BC".to_string()]);
+    /// ```
+    pub fn to_uppercase(&self) -> Veclite<String> {
+        Veclite::wrap(self.0.iter().map(|s| s.to_uppercase()).collect())
+    }
+
+    /// Returns a new list with every string converted to lowercase.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel!["a".to_string(), "Bc".to_string()];
+    /// assert_eq!(v.to_lowercase(), vel!["a".to_string(), "bc".to_string()]);
+    /// ```
+    pub fn to_lowercase(&self) -> Veclite<String> {
+        Veclite::wrap(self.0.iter().map(|s| s.to_lowercase()).collect())
+    }
+}
+
+impl<S: AsRef<str>> Veclite<S> {
+    /// Concatenates the string elements, placing `sep` between each pair.
+    ///
+    /// Works for both `Vel<String>` and `Vel<&str>`, and copies the strings directly
+    /// rather than going through `Display`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel!["a", "b", "c"].join("-"), "a-b-c");
+    /// assert_eq!(vel!["x".to_string(), "y".to_string()].join(", "), "x, y");
+    /// ```
+    pub fn join(&self, sep: &str) -> String {
+        let len = self.0.iter().map(|s| s.as_ref().len()).sum::<usize>()
+            + sep.len() * self.0.len().saturating_sub(1);
+        let mut out = String::with_capacity(len);
+        for (i, s) in self.0.iter().enumerate() {
+            if i > 0 {
+                out.push_str(sep);
+            }
+            out.push_str(s.as_ref());
+        }
+        out
+    }
+
+    /// Returns a `Display` adapter that joins the strings with single spaces, starting a
+    /// new line whenever the next word would push the line past `max_cols` characters.
+    ///
+    /// A word longer than `max_cols` is placed on a line of its own.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel!["the", "quick", "brown", "fox", "jumps"];
+    /// assert_eq!(
+    ///     format!("{}", v.display_wrapped_width(10)),
+    ///     "the quick\nbrown fox\njumps"
+    /// );
+    /// ```
+    pub fn display_wrapped_width(&self, max_cols: usize) -> impl Display + '_ {
+        display_fn(move |f| {
+            let mut col = 0;
+            for (i, word) in self.0.iter().enumerate() {
+                let word = word.as_ref();
+                let len = word.chars().count();
+                if i == 0 {
+                    col = len;
+                } else if col + 1 + len > max_cols {
+                    f.write_str("\n")?;
+                    col = len;
+                } else {
+                    f.write_str(" ")?;
+                    col += 1 + len;
+                }
+                f.write_str(word)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl Veclite<bool> {
+    /// Returns the number of `true` flags.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![true, false, true].count_true(), 2);
+    /// ```
+    pub fn count_true(&self) -> usize {
+        self.0.iter().filter(|&&b| b).count()
+    }
+
+    /// Returns the number of `false` flags.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert_eq!(vel![true, false, true].count_false(), 1);
+    /// ```
+    pub fn count_false(&self) -> usize {
+        self.0.len() - self.count_true()
+    }
+
+    /// Returns `true` if every flag is `true`, including for an empty list.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// assert!(!vel![true, false, true].all_true());
+    /// assert!(vel![true, true].all_true());
+    /// ```
+    pub fn all_true(&self) -> bool {
+        self.0.iter().all(|&b| b)
+    }
+}
+
+impl<T: Display> Veclite<Veclite<T>> {
+    /// Returns a `Display` adapter for a nested list that wraps each inner list in
+    /// brackets, so `vel![vel![1, 2], vel![3]]` prints as `[1 2] [3]`.
+    ///
+    /// The plain `Display` impl cannot be specialized for nested lists on stable Rust,
+    /// so `{}` still prints the flattened-looking `1 2 3`; use this adapter when the
+    /// grouping matters.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let v = vel![vel![1, 2], vel![3]];
+    /// assert_eq!(format!("{}", v.display_nested()), "[1 2] [3]");
+    /// assert_eq!(format!("{}", v), "1 2 3");
+    /// ```
+    pub fn display_nested(&self) -> impl Display + '_ {
+        display_fn(move |f| {
+            for (i, inner) in self.0.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(" ")?;
+                }
+                write!(f, "[{}]", inner)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V> Veclite<(K, V)> {
+    /// Collects the key-value pairs into a `HashMap`. Requires the `std` feature.
+    ///
+    /// Later pairs overwrite earlier ones with the same key, as with
+    /// `HashMap::from_iter`.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::vel;
+    /// let map = vel![(1, "a"), (2, "b"), (1, "c")].into_hashmap();
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map[&1], "c");
+    /// assert_eq!(map[&2], "b");
+    /// ```
+    pub fn into_hashmap(self) -> std::collections::HashMap<K, V> {
+        self.0.into_iter().collect()
+    }
+}
+
+/// A reusable formatting preset for [`Veclite::display_with`].
+///
+/// # Example
+/// ```
+/// use veclite::{vel, Separator};
+/// const PIPES: Separator = Separator { prefix: "| ", item_sep: " | ", suffix: " |" };
+/// assert_eq!(format!("{}", vel![1, 2].display_with(&PIPES)), "| 1 | 2 |");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Separator {
+    /// Written before the first element.
+    pub prefix: &'static str,
+    /// Written between adjacent elements.
+    pub item_sep: &'static str,
+    /// Written after the last element.
+    pub suffix: &'static str,
+}
+
+impl Separator {
+    /// Comma-and-space separated, with no delimiters: `1, 2, 3`.
+    pub const COMMA: Separator = Separator {
+        prefix: "",
+        item_sep: ", ",
+        suffix: "",
+    };
+
+    /// Space separated, with no delimiters, like the default `Display`: `1 2 3`.
+    pub const SPACE: Separator = Separator {
+        prefix: "",
+        item_sep: " ",
+        suffix: "",
+    };
+}
+
+impl Veclite<char> {
+    /// Concatenates the characters into a `String` with no separator.
+    ///
+    /// This deliberately differs from the `Display` output, which is still available
+    /// through `format!("{}", v)`. In 1.x, `to_string()` on a `Vel<char>` went through
+    /// `Display` and returned the space-separated text; use `format!` to keep that.
+    ///
+    /// # Example
+    /// ```
+    /// use veclite::Vel;
+    /// let v = Vel::from("hello");
+    /// assert_eq!(v.to_string(), "hello");
+    /// assert_eq!(format!("{}", v), "h e l l o");
+    /// ```
+    #[allow(clippy::inherent_to_string_shadow_display)]
+    pub fn to_string(&self) -> String {
+        self.0.iter().collect()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T> Veclite<(T, f64)> {
+    /// Picks an element at random with probability proportional to its weight.
+    /// Requires the `rand` feature.
+    ///
+    /// Negative and NaN weights are treated as zero. Returns `None` if the list is
+    /// empty or no element has a positive weight.
+    ///
+    /// Weights are scaled by the largest one before drawing, so finite weights whose sum
+    /// would overflow still work. If any weight is infinite, the pick is uniform among
+    /// the elements with infinite weight.
+    ///
+    /// # Example
+    /// ```
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    /// use veclite::vel;
+    ///
+    /// let v = vel![('a', 1.0), ('b', 3.0), ('c', 0.0)];
+    /// let mut rng = SmallRng::seed_from_u64(7);
+    /// let draws = 10_000;
+    /// let mut counts = [0usize; 3];
+    /// for _ in 0..draws {
+    ///     match v.weighted_choice(&mut rng) {
+    ///         Some('a') => counts[0] += 1,
+    ///         Some('b') => counts[1] += 1,
+    ///         _ => counts[2] += 1,
+    ///     }
+    /// }
+    /// let b_share = counts[1] as f64 / draws as f64;
+    /// assert!((b_share - 0.75).abs() < 0.03);
+    /// assert_eq!(counts[2], 0);
+    ///
+    /// assert_eq!(vel![('x', 0.0)].weighted_choice(&mut rng), None);
+    ///
+    /// let huge = vel![('a', f64::MAX), ('b', f64::MAX)];
+    /// let a_count = (0..1000)
+    ///     .filter(|_| huge.weighted_choice(&mut rng) == Some(&'a'))
+    ///     .count();
+    /// assert!((400..600).contains(&a_count));
+    ///
+    /// let infinite = vel![('a', 1.0), ('b', f64::INFINITY)];
+    /// assert!((0..100).all(|_| infinite.weighted_choice(&mut rng) == Some(&'b')));
+    /// ```
+    pub fn weighted_choice<R: rand::Rng>(&self, rng: &mut R) -> Option<&T> {
+        let largest = self
+            .0
+            .iter()
+            .map(|&(_, w)| w)
+            .filter(|&w| w > 0.0)
+            .fold(0.0, f64::max);
+        if largest == 0.0 {
+            return None;
+        }
+        let weight = |w: f64| {
+            if w.is_nan() || w <= 0.0 {
+                0.0
+            } else if largest.is_infinite() {
+                // Infinite weights form their own bucket and outweigh every finite one.
+                if w.is_infinite() {
+                    1.0
+                } else {
+                    0.0
+                }
+            } else {
+                w / largest
+            }
+        };
+        let total: f64 = self.0.iter().map(|&(_, w)| weight(w)).sum();
+        let mut target = rng.random::<f64>() * total;
+        let mut last = None;
+        for (item, w) in &self.0 {
+            let w = weight(*w);
+            if w == 0.0 {
+                continue;
+            }
+            if target < w {
+                return Some(item);
+            }
+            target -= w;
+            last = Some(item);
+        }
+        // Rounding can leave `target` just past the final bucket.
+        last
+    }
+}
+
 /// Short alias for [`Veclite<T>`], for ergonomic use.
 ///
 /// # Example
@@ -146,6 +2947,7 @@ pub type Vel<T> = Veclite<T>;
 ///
 /// # Examples
 /// ```
+/// use veclite::{vel, Vel};
 /// let v = vel![1, 2, 3];
 /// let empty: Vel<i32> = vel![];
 /// ```